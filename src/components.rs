@@ -1,11 +1,11 @@
 //! [Component]s and [Bundle]s used by the plugin.
 
 pub use crate::ldtk::EntityInstance;
-use crate::ldtk::{LayerInstance, Type};
+use crate::ldtk::{LayerInstance, Level, Type};
 use bevy::prelude::*;
 
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
@@ -17,7 +17,7 @@ use crate::{
     utils::ldtk_grid_coords_to_grid_coords,
 };
 
-use bevy_ecs_tilemap::tiles::{TileBundle, TilePos};
+use bevy_ecs_tilemap::tiles::{TileBundle, TileFlip as TilemapTileFlip, TilePos};
 
 /// [Component] added to any `IntGrid` tile by default.
 ///
@@ -87,10 +87,10 @@ impl Worldly {
 /// Then, it will be spawned with the initial grid-based position of the entity in LDtk.
 /// See [LdtkEntity#grid_coords] for attribute macro usage.
 ///
-/// Note that the plugin will not automatically update the entity's [Transform] when this component
-/// is updated, nor visa versa.
-/// This is left up to the user since there are plenty of scenarios where this behavior needs to be
-/// custom.
+/// By default, the plugin does not automatically update the entity's [Transform] when this
+/// component is updated, nor visa versa, since there are plenty of scenarios where this behavior
+/// needs to be custom.
+/// Opt in to a basic grid-to-transform sync with [GridCoordsPlugin] and [SyncGridCoordsToTransform].
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash, Component, Reflect)]
 #[reflect(Component)]
 pub struct GridCoords {
@@ -197,6 +197,89 @@ impl GridCoords {
     }
 }
 
+/// [Component] that stores a tile's horizontal/vertical mirroring, decoded from LDtk's packed
+/// tile flip bits.
+///
+/// For Tile and AutoTile layers, all tiles have this component by default, alongside the
+/// corresponding `bevy_ecs_tilemap` [TilemapTileFlip] that's populated with the same information
+/// so the tile renders mirrored correctly.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash, Component, Reflect)]
+#[reflect(Component)]
+pub struct TileFlip {
+    pub x: bool,
+    pub y: bool,
+}
+
+impl From<i32> for TileFlip {
+    /// Decodes LDtk's packed tile flip bits.
+    ///
+    /// Bit `0b01` is the x-flip, bit `0b10` is the y-flip.
+    fn from(bits: i32) -> Self {
+        TileFlip {
+            x: bits & 0b01 != 0,
+            y: bits & 0b10 != 0,
+        }
+    }
+}
+
+impl From<TileFlip> for i32 {
+    /// Re-encodes a [TileFlip] into LDtk's packed tile flip bits.
+    fn from(flip: TileFlip) -> Self {
+        flip.x as i32 | (flip.y as i32) << 1
+    }
+}
+
+impl From<TileFlip> for TilemapTileFlip {
+    fn from(flip: TileFlip) -> Self {
+        TilemapTileFlip {
+            x: flip.x,
+            y: flip.y,
+            ..Default::default()
+        }
+    }
+}
+
+/// Marker [Component] that opts an entity into [GridCoordsPlugin]'s [GridCoords]-to-[Transform]
+/// synchronization.
+///
+/// The entity also needs a [GridCoords] and a [Transform].
+/// Stores the grid size to convert with directly, rather than looking it up from a parent's
+/// [LayerMetadata], since the entity's immediate parent isn't guaranteed to carry one: by default
+/// [LdtkEntity]s are parented to the level entity (which has no grid size of its own), and
+/// [Worldly] entities reparent again after that.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash, Component, Reflect)]
+#[reflect(Component)]
+pub struct SyncGridCoordsToTransform {
+    pub grid_size: i32,
+}
+
+/// [Plugin] that provides an opt-in [GridCoords]-to-[Transform] synchronization system.
+///
+/// Add [SyncGridCoordsToTransform] to any entity with a [GridCoords] and a [Transform] to have its
+/// translation kept in lockstep with its [GridCoords], using the grid size stored in
+/// [SyncGridCoordsToTransform].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GridCoordsPlugin;
+
+impl Plugin for GridCoordsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(transform_from_grid_coords);
+    }
+}
+
+fn transform_from_grid_coords(
+    mut grid_coords_query: Query<
+        (&GridCoords, &mut Transform, &SyncGridCoordsToTransform),
+        Changed<GridCoords>,
+    >,
+) {
+    for (grid_coords, mut transform, sync) in grid_coords_query.iter_mut() {
+        let grid_size = sync.grid_size as f32;
+        transform.translation.x = grid_coords.x as f32 * grid_size + grid_size / 2.;
+        transform.translation.y = grid_coords.y as f32 * grid_size + grid_size / 2.;
+    }
+}
+
 /// [Component] for storing user-defined custom data for a paticular tile in an LDtk tileset
 /// definition.
 ///
@@ -313,6 +396,382 @@ impl From<&LayerInstance> for LayerMetadata {
     }
 }
 
+/// [Component] for storing some LDtk level information on level entities.
+///
+/// Based on [Level], but without the fields containing layer and other asset information.
+///
+/// Inserted on the level entity once its [LdtkLevel] asset has loaded, by [LdtkMetadataPlugin].
+#[derive(Clone, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct LevelMetadata {
+    /// World X coordinate in pixels
+    pub world_x: i32,
+
+    /// World Y coordinate in pixels
+    pub world_y: i32,
+
+    /// Width of the level in pixels
+    pub px_wid: i32,
+
+    /// Height of the level in pixels
+    pub px_hei: i32,
+
+    /// Background color of the level (copied from the project's `default_level_bg_color`,
+    /// unless it was overridden by this level)
+    pub bg_color: String,
+
+    /// Relative path to the level's background image, if any
+    pub bg_rel_path: Option<String>,
+
+    /// Unique instance identifier
+    pub uid: i32,
+
+    /// Unique level identifier
+    pub identifier: String,
+}
+
+impl From<&Level> for LevelMetadata {
+    fn from(level: &Level) -> Self {
+        LevelMetadata {
+            world_x: level.world_x,
+            world_y: level.world_y,
+            px_wid: level.px_wid,
+            px_hei: level.px_hei,
+            bg_color: level.bg_color.clone(),
+            bg_rel_path: level.bg_rel_path.clone(),
+            uid: level.uid,
+            identifier: level.identifier.clone(),
+        }
+    }
+}
+
+/// [Plugin] that inserts metadata components onto level and layer entities once their LDtk data
+/// has loaded.
+///
+/// Currently inserts [LevelMetadata] and [LevelNeighbors] on level entities, and [IntGridLayer]
+/// on IntGrid layer entities.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LdtkMetadataPlugin;
+
+impl Plugin for LdtkMetadataPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(insert_level_metadata)
+            .add_system(insert_int_grid_layer);
+    }
+}
+
+fn insert_level_metadata(
+    mut commands: Commands,
+    level_query: Query<(Entity, &Handle<LdtkLevel>), Without<LevelMetadata>>,
+    ldtk_level_assets: Res<Assets<LdtkLevel>>,
+) {
+    for (level_entity, level_handle) in level_query.iter() {
+        if let Some(ldtk_level) = ldtk_level_assets.get(level_handle) {
+            let level = &ldtk_level.level;
+            commands
+                .entity(level_entity)
+                .insert(LevelMetadata::from(level))
+                .insert(LevelNeighbors::from(level));
+        }
+    }
+}
+
+/// [Component] for storing the decoded IntGrid values of an IntGrid layer as a 2D grid.
+///
+/// Inserted on IntGrid layer entities once their [LdtkLevel] asset has loaded, by
+/// [LdtkMetadataPlugin].
+///
+/// Converts LDtk's top-left-origin, row-major `int_grid_csv` into the crate's bottom-left-origin
+/// [GridCoords] convention, so it can be indexed directly with the same [GridCoords] values used
+/// by the layer's [IntGridCell] tiles. A value of `0` means the cell is empty; LDtk IntGrid
+/// values start at `1`.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct IntGridLayer {
+    grid: Vec<i32>,
+    c_wid: i32,
+    c_hei: i32,
+}
+
+impl IntGridLayer {
+    /// Returns the IntGrid value at the given [GridCoords], or [None] if out of bounds.
+    pub fn get(&self, grid_coords: GridCoords) -> Option<i32> {
+        if grid_coords.x < 0
+            || grid_coords.x >= self.c_wid
+            || grid_coords.y < 0
+            || grid_coords.y >= self.c_hei
+        {
+            return None;
+        }
+
+        let index = (self.c_hei - 1 - grid_coords.y) * self.c_wid + grid_coords.x;
+        self.grid.get(index as usize).copied()
+    }
+
+    /// Returns the IntGrid values of the cardinal (North, South, East, West) neighbors of the
+    /// given [GridCoords], in that order.
+    pub fn cardinal_neighbors(&self, grid_coords: GridCoords) -> [Option<i32>; 4] {
+        [
+            self.get(grid_coords + GridCoords::new(0, 1)),
+            self.get(grid_coords + GridCoords::new(0, -1)),
+            self.get(grid_coords + GridCoords::new(1, 0)),
+            self.get(grid_coords + GridCoords::new(-1, 0)),
+        ]
+    }
+
+    /// Returns the IntGrid values of all 8 neighbors of the given [GridCoords], clockwise
+    /// starting from North.
+    pub fn neighbors(&self, grid_coords: GridCoords) -> [Option<i32>; 8] {
+        [
+            self.get(grid_coords + GridCoords::new(0, 1)),
+            self.get(grid_coords + GridCoords::new(1, 1)),
+            self.get(grid_coords + GridCoords::new(1, 0)),
+            self.get(grid_coords + GridCoords::new(1, -1)),
+            self.get(grid_coords + GridCoords::new(0, -1)),
+            self.get(grid_coords + GridCoords::new(-1, -1)),
+            self.get(grid_coords + GridCoords::new(-1, 0)),
+            self.get(grid_coords + GridCoords::new(-1, 1)),
+        ]
+    }
+}
+
+impl From<&LayerInstance> for IntGridLayer {
+    fn from(instance: &LayerInstance) -> Self {
+        IntGridLayer {
+            grid: instance.int_grid_csv.clone(),
+            c_wid: instance.c_wid,
+            c_hei: instance.c_hei,
+        }
+    }
+}
+
+fn insert_int_grid_layer(
+    mut commands: Commands,
+    layer_query: Query<(Entity, &LayerMetadata, &Parent), Without<IntGridLayer>>,
+    level_query: Query<&Handle<LdtkLevel>>,
+    ldtk_level_assets: Res<Assets<LdtkLevel>>,
+) {
+    for (layer_entity, layer_metadata, parent) in layer_query.iter() {
+        if layer_metadata.layer_instance_type != Type::IntGrid {
+            continue;
+        }
+
+        if let Ok(level_handle) = level_query.get(parent.get()) {
+            if let Some(ldtk_level) = ldtk_level_assets.get(level_handle) {
+                let layer_instance = ldtk_level.level.layer_instances.as_ref().and_then(|layers| {
+                    layers.iter().find(|layer| layer.iid == layer_metadata.iid)
+                });
+
+                if let Some(layer_instance) = layer_instance {
+                    commands
+                        .entity(layer_entity)
+                        .insert(IntGridLayer::from(layer_instance));
+                }
+            }
+        }
+    }
+}
+
+/// The cardinal/diagonal direction of a [LevelNeighbors] entry, as stored in LDtk's `neighbours`
+/// field.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum NeighbourDirection {
+    North,
+    South,
+    East,
+    West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl NeighbourDirection {
+    /// Parses LDtk's `dir` field of a level neighbour.
+    ///
+    /// Returns [None] for directions that aren't a cardinal/diagonal border, such as the `<` and
+    /// `>` values used for world-depth neighbours.
+    fn parse(dir: &str) -> Option<NeighbourDirection> {
+        match dir {
+            "n" => Some(NeighbourDirection::North),
+            "s" => Some(NeighbourDirection::South),
+            "e" => Some(NeighbourDirection::East),
+            "w" => Some(NeighbourDirection::West),
+            "ne" => Some(NeighbourDirection::NorthEast),
+            "nw" => Some(NeighbourDirection::NorthWest),
+            "se" => Some(NeighbourDirection::SouthEast),
+            "sw" => Some(NeighbourDirection::SouthWest),
+            _ => None,
+        }
+    }
+}
+
+/// [Component] for storing the levels bordering a level entity, as defined by LDtk's
+/// `neighbours` field.
+///
+/// Inserted on the level entity once its [LdtkLevel] asset has loaded, by [LdtkMetadataPlugin].
+///
+/// Can be used alongside [LevelSet] to implement level-to-level navigation, inserting a
+/// neighbouring level's `iid` into the [LevelSet] when the player approaches that border.
+///
+/// Like [LevelSet], this component does not derive [Reflect], since
+/// `HashMap<NeighbourDirection, String>` doesn't support it.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Component)]
+pub struct LevelNeighbors {
+    neighbors: HashMap<NeighbourDirection, String>,
+}
+
+impl LevelNeighbors {
+    /// Returns the `iid` of the neighbouring level in the given direction, if any.
+    pub fn get(&self, direction: NeighbourDirection) -> Option<&String> {
+        self.neighbors.get(&direction)
+    }
+
+    /// Iterates through this level's neighbours' directions and `iid`s.
+    pub fn iter(&self) -> impl Iterator<Item = (&NeighbourDirection, &String)> {
+        self.neighbors.iter()
+    }
+}
+
+impl From<&Level> for LevelNeighbors {
+    fn from(level: &Level) -> Self {
+        LevelNeighbors {
+            neighbors: level
+                .neighbours
+                .iter()
+                .filter_map(|neighbour| {
+                    NeighbourDirection::parse(&neighbour.dir)
+                        .map(|direction| (direction, neighbour.level_iid.clone()))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The decoded crop/scale/placement of a level's background image, mirroring LDtk's `bgPos`.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Reflect)]
+pub struct LevelBackgroundPosition {
+    /// Top-left corner of the image, in level pixel coordinates
+    pub top_left_px: Vec2,
+    /// Horizontal and vertical scale applied to the cropped image
+    pub scale: Vec2,
+    /// Top-left corner of the crop rectangle within the source image, in pixels
+    pub crop_top_left_px: Vec2,
+    /// Width and height of the crop rectangle within the source image, in pixels
+    pub crop_size_px: Vec2,
+}
+
+impl From<&crate::ldtk::LevelBackgroundPosition> for LevelBackgroundPosition {
+    fn from(bg_pos: &crate::ldtk::LevelBackgroundPosition) -> Self {
+        LevelBackgroundPosition {
+            top_left_px: Vec2::new(bg_pos.top_left_px[0] as f32, bg_pos.top_left_px[1] as f32),
+            scale: Vec2::new(bg_pos.scale[0], bg_pos.scale[1]),
+            crop_top_left_px: Vec2::new(bg_pos.crop_rect[0], bg_pos.crop_rect[1]),
+            crop_size_px: Vec2::new(bg_pos.crop_rect[2], bg_pos.crop_rect[3]),
+        }
+    }
+}
+
+/// [Component] storing a level's background rendering information, decoded from [Level].
+///
+/// Inserted on the level entity once its [LdtkLevel] asset has loaded, by
+/// [LevelBackgroundPlugin]. A background sprite is spawned as a child of the level entity to
+/// match this information: filled with `color`, and if `rel_path` is present, the loaded image is
+/// cropped, scaled, and positioned according to `position` to match the LDtk editor preview.
+#[derive(Clone, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct LevelBackground {
+    pub color: Color,
+    pub rel_path: Option<String>,
+    pub position: Option<LevelBackgroundPosition>,
+}
+
+impl From<&Level> for LevelBackground {
+    fn from(level: &Level) -> Self {
+        LevelBackground {
+            color: Color::hex(level.bg_color.trim_start_matches('#')).unwrap_or(Color::NONE),
+            rel_path: level.bg_rel_path.clone(),
+            position: level.bg_pos.as_ref().map(LevelBackgroundPosition::from),
+        }
+    }
+}
+
+/// [Plugin] that spawns a background sprite, as a child of the level entity, for every level
+/// whose [LdtkLevel] asset has loaded.
+///
+/// Opt-in: the base plugin doesn't spawn a visual for `bg_color`/`bg_rel_path` on its own, so add
+/// this plugin to close that gap between the LDtk editor preview and the running game.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LevelBackgroundPlugin;
+
+impl Plugin for LevelBackgroundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(insert_level_background)
+            .add_system(spawn_level_background_sprites.after(insert_level_background));
+    }
+}
+
+fn insert_level_background(
+    mut commands: Commands,
+    level_query: Query<(Entity, &Handle<LdtkLevel>), Without<LevelBackground>>,
+    ldtk_level_assets: Res<Assets<LdtkLevel>>,
+) {
+    for (level_entity, level_handle) in level_query.iter() {
+        if let Some(ldtk_level) = ldtk_level_assets.get(level_handle) {
+            commands
+                .entity(level_entity)
+                .insert(LevelBackground::from(&ldtk_level.level));
+        }
+    }
+}
+
+fn spawn_level_background_sprites(
+    mut commands: Commands,
+    level_query: Query<
+        (Entity, &LevelBackground),
+        (Added<LevelBackground>, With<Handle<LdtkLevel>>),
+    >,
+    asset_server: Res<AssetServer>,
+) {
+    for (level_entity, level_background) in level_query.iter() {
+        let (texture, rect, custom_size, translation) = match &level_background.position {
+            Some(position) => (
+                level_background
+                    .rel_path
+                    .as_ref()
+                    .map(|rel_path| asset_server.load(rel_path))
+                    .unwrap_or_default(),
+                Some(Rect {
+                    min: position.crop_top_left_px,
+                    max: position.crop_top_left_px + position.crop_size_px,
+                }),
+                Some(position.crop_size_px * position.scale),
+                position.top_left_px.extend(1.),
+            ),
+            None => (Handle::default(), None, None, Vec3::new(0., 0., -1.)),
+        };
+
+        let background_entity = commands
+            .spawn_bundle(LevelBackgroundBundle {
+                sprite_bundle: SpriteBundle {
+                    sprite: Sprite {
+                        color: level_background.color,
+                        custom_size,
+                        rect,
+                        ..Default::default()
+                    },
+                    texture,
+                    transform: Transform::from_translation(translation),
+                    ..Default::default()
+                },
+                level_background: level_background.clone(),
+            })
+            .id();
+
+        commands.entity(level_entity).add_child(background_entity);
+    }
+}
+
 /// [Component] that indicates that an LDtk level or world should respawn.
 ///
 /// Inserting this component on an entity with either `Handle<LdtkAsset>` or `Handle<LdtkLevel>`
@@ -328,6 +787,22 @@ pub(crate) struct TileGridBundle {
     #[bundle]
     pub tile_bundle: TileBundle,
     pub grid_coords: GridCoords,
+    pub tile_flip: TileFlip,
+}
+
+impl TileGridBundle {
+    /// Builds a [TileGridBundle], decoding `flip_bits` into a [TileFlip] and populating both it
+    /// and `tile_bundle`'s own [TilemapTileFlip] so the tile renders mirrored correctly.
+    pub(crate) fn new(mut tile_bundle: TileBundle, grid_coords: GridCoords, flip_bits: i32) -> Self {
+        let tile_flip = TileFlip::from(flip_bits);
+        tile_bundle.flip = tile_flip.into();
+
+        TileGridBundle {
+            tile_bundle,
+            grid_coords,
+            tile_flip,
+        }
+    }
 }
 
 #[derive(Clone, Default, Bundle)]
@@ -340,6 +815,13 @@ pub(crate) struct EntityInstanceBundle {
     pub entity_instance: EntityInstance,
 }
 
+#[derive(Clone, Default, Bundle)]
+pub(crate) struct LevelBackgroundBundle {
+    #[bundle]
+    pub sprite_bundle: SpriteBundle,
+    pub level_background: LevelBackground,
+}
+
 /// [Bundle] for spawning LDtk worlds and their levels. The main bundle for using this plugin.
 ///
 /// After the ldtk file is done loading, the levels you've chosen with [LevelSelection] or
@@ -354,6 +836,9 @@ pub(crate) struct EntityInstanceBundle {
 ///
 /// For Entity layers, all LDtk entities in the level are spawned as children to the level entity,
 /// unless marked by a [Worldly] component.
+///
+/// If [LevelBackgroundPlugin] is added, each level entity will also have a [LevelBackground]
+/// component and a background sprite child.
 #[derive(Clone, Default, Bundle)]
 pub struct LdtkWorldBundle {
     pub ldtk_handle: Handle<crate::assets::LdtkAsset>,